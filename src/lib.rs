@@ -1,8 +1,21 @@
+use std::io::BufRead;
+
+#[cfg(feature = "serde")]
+pub mod serde_impl;
 
 pub struct MiniON {
     pub name: String,
     pub length: usize,
     pub content: Option<String>,
+    content_bytes: Option<Vec<u8>>,
+}
+
+/// Resumable state for [`MiniON::parse_from_reader`], so a fresh `fill_buf` only re-scans the
+/// field currently being parsed instead of the whole object from byte zero.
+enum ReaderParseState {
+    Name,
+    Length { name: String, start: usize },
+    Content { name: String, length: usize, start: usize },
 }
 
 impl MiniON {
@@ -12,6 +25,28 @@ impl MiniON {
             name,
             length: 0,
             content: None,
+            content_bytes: None,
+        }
+    }
+
+    /// Construct a new binary `MiniON` directly from raw bytes, without requiring `content` to be valid UTF-8.
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let minion = MiniON::new_binary("image".to_string(), vec![0xFF, 0x00, b'|', b'~']);
+    ///
+    ///     assert_eq!(Some(vec![0xFF, 0x00, b'|', b'~']),minion.content_bytes());
+    /// ```
+    pub fn new_binary(name: String, content: Vec<u8>) -> MiniON {
+        let length = content.len();
+        let as_text = String::from_utf8(content.clone()).ok();
+
+        MiniON {
+            name,
+            length,
+            content: as_text,
+            content_bytes: Some(content),
         }
     }
 
@@ -19,36 +54,73 @@ impl MiniON {
     pub fn set_content(&mut self, content: String) {
         self.length = content.len();
         self.content = Some(content);
+        self.content_bytes = None;
+    }
+
+    /// Return the raw bytes of `content`, if any.
+    pub fn content_bytes(&self) -> Option<Vec<u8>> {
+        match &self.content_bytes {
+            Some(bytes) => Some(bytes.clone()),
+            None => self.content.as_ref().map(|content| content.as_bytes().to_vec()),
+        }
     }
 
-    /// Return the `MiniON` as a `String`.
+    /// Return the `MiniON` as a `String`. Errors with `Error::BadStructure` if `content` is not
+    /// valid UTF-8 (a `MiniON` built with [`MiniON::new_binary`] from non-UTF-8 bytes) — use
+    /// [`MiniON::as_bytes`] instead for those.
     /// ## Example
     /// ```rust
     ///     use minimal_object_notation::*;
-    /// 
+    ///
     ///     let mut minion = MiniON::new("greeting".to_string());
-    /// 
+    ///
     ///     minion.set_content("Hello, world!".to_string());
-    /// 
-    ///     let minion = minion.as_string();
+    ///
+    ///     let minion = minion.as_string().unwrap();
     /// ```
     /// Will give you a `String` containing `"greeting|13~Hello, world!"`.
-    pub fn as_string(&self) -> String {
+    pub fn as_string(&self) -> Result<String,Error> {
+        let content = match &self.content {
+            Some(content) => content,
+            None => {
+                if self.content_bytes.is_some() {
+                    return Err(Error::BadStructure("Content is not valid UTF-8; use MiniON::as_bytes instead.".to_string()));
+                }
+
+                ""
+            }
+        };
+
         let mut output = String::from(&self.name);
         output.push('|');
         output.push_str(&format!("{}",self.length));
         output.push('~');
-        
-        match &self.content {
-            Some(content) => {
-                output.push_str(&content);
+        output.push_str(content);
 
-                return output;
-            },
-            None => {
-                return output;
-            }
-        } 
+        return Ok(output);
+    }
+
+    /// Return the `MiniON` as raw bytes, the binary-safe counterpart to `as_string`.
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let minion = MiniON::new_binary("image".to_string(), vec![0xFF, 0x00, b'|', b'~']);
+    ///
+    ///     assert_eq!(b"image|4~\xFF\x00|~".to_vec(),minion.as_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        output.extend_from_slice(self.name.as_bytes());
+        output.push(b'|');
+        output.extend_from_slice(self.length.to_string().as_bytes());
+        output.push(b'~');
+
+        if let Some(content) = self.content_bytes() {
+            output.extend_from_slice(&content);
+        }
+
+        return output;
     }
 
     /// Parse data into a `MiniON` object.
@@ -185,6 +257,121 @@ impl MiniON {
         }
     }
 
+    /// Parse a single `MiniON` object by pulling bytes from a `BufRead` on demand.
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///     use std::io::BufReader;
+    ///
+    ///     let data = b"greeting|13~Hello, world!";
+    ///
+    ///     let mut reader = BufReader::new(&data[..]);
+    ///
+    ///     match MiniON::parse_from_reader(&mut reader) {
+    ///         Ok(minion) => {
+    ///             assert_eq!("greeting",minion.name);
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn parse_from_reader<R: BufRead>(reader: &mut R) -> Result<MiniON,Error> {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut state = ReaderParseState::Name;
+
+        loop {
+            state = match state {
+                ReaderParseState::Name => {
+                    let mut incr: usize = 0;
+
+                    match MiniON::parse_name(&buffer, &mut incr) {
+                        Ok(name) => {
+                            ReaderParseState::Length { name, start: incr }
+                        },
+                        Err(Error::Needed(_)) => {
+                            MiniON::fill_from_reader(reader, &mut buffer)?;
+
+                            ReaderParseState::Name
+                        },
+                        Err(e) => {
+                            return Err(e);
+                        }
+                    }
+                },
+                ReaderParseState::Length { name, start } => {
+                    let mut incr = start;
+
+                    match MiniON::parse_length(&buffer, &mut incr, &name) {
+                        Ok(0) => {
+                            return Ok(MiniON::new(name));
+                        },
+                        Ok(length) => {
+                            ReaderParseState::Content { name, length, start: incr }
+                        },
+                        Err(Error::Needed(_)) => {
+                            MiniON::fill_from_reader(reader, &mut buffer)?;
+
+                            ReaderParseState::Length { name, start }
+                        },
+                        Err(e) => {
+                            return Err(e);
+                        }
+                    }
+                },
+                ReaderParseState::Content { name, length, start } => {
+                    let mut incr = start;
+
+                    match MiniON::parse_content_bytes(&buffer, &mut incr, length) {
+                        Ok(content_bytes) => {
+                            match String::from_utf8(content_bytes) {
+                                Ok(content) => {
+                                    let mut minion = MiniON::new(name);
+                                    minion.set_content(content);
+
+                                    return Ok(minion);
+                                },
+                                Err(_) => {
+                                    return Err(Error::BadStructure(format!("The content starting at position {} is not valid UTF-8.",start)));
+                                }
+                            }
+                        },
+                        Err(Error::Needed(_)) => {
+                            MiniON::fill_from_reader(reader, &mut buffer)?;
+
+                            ReaderParseState::Content { name, length, start }
+                        },
+                        Err(e) => {
+                            return Err(e);
+                        }
+                    }
+                }
+            };
+        }
+    }
+
+    /// Pull one more chunk of bytes from `reader` into `buffer`, for resuming [`MiniON::parse_from_reader`]'s state machine.
+    fn fill_from_reader<R: BufRead>(reader: &mut R, buffer: &mut Vec<u8>) -> Result<(),Error> {
+        match reader.fill_buf() {
+            Ok(chunk) => {
+                match chunk.len() {
+                    0 => {
+                        Err(Error::Incomplete(format!("The reader reached end-of-stream with {} byte(s) already buffered.",buffer.len())))
+                    },
+                    n => {
+                        buffer.extend_from_slice(chunk);
+                        reader.consume(n);
+
+                        Ok(())
+                    }
+                }
+            },
+            Err(e) => {
+                Err(Error::BadStructure(format!("Could not read from the underlying reader: {}",e)))
+            }
+        }
+    }
+
     /// Parse the name of a miniON object. (Start at the correct position.)
     /// ## Example
     /// ```rust
@@ -205,37 +392,32 @@ impl MiniON {
     ///     }
     /// ```
     pub fn parse_name(bytes: &[u8], incr: &mut usize) -> Result<String,Error> {
-        let mut output = String::new();
-    
+        let start = *incr;
+
         loop {
-    
-            match bytes[*incr] as char {
-                '|' => {
-                    match *incr + 1 < bytes.len() {
-                        true => {
-                            *incr += 1;
-                        },
-                        false => {
-                            return Err(Error::Incomplete(format!("No more data after name ({}) field at position {}.",output,*incr)));
-                        }
-                    }
-    
-                    return Ok(output);
-                },
-                c => {
-                    output.push(c);
-                }
+
+            if *incr >= bytes.len() {
+                return Err(Error::Needed(1));
             }
-    
-            match *incr + 1 < bytes.len() {
-                true => {
+
+            match bytes[*incr] {
+                b'|' => {
+                    let name = match std::str::from_utf8(&bytes[start..*incr]) {
+                        Ok(name) => name.to_string(),
+                        Err(_) => {
+                            return Err(Error::BadStructure(format!("The name field starting at position {} is not valid UTF-8.",start)));
+                        }
+                    };
+
                     *incr += 1;
+
+                    return Ok(name);
                 },
-                false => {
-                    return Err(Error::NoStructure);
+                _ => {
+                    *incr += 1;
                 }
             }
-    
+
         }
     }
 
@@ -260,58 +442,33 @@ impl MiniON {
     /// ```
     pub fn parse_length(bytes: &[u8], incr: &mut usize, name: &str) -> Result<usize,Error> {
         let mut output = String::new();
-    
+
         loop {
-    
+
+            if *incr >= bytes.len() {
+                return Err(Error::Needed(1));
+            }
+
             match bytes[*incr] as char {
                 '~' => {
-                    match *incr + 1 < bytes.len() {
-                        true => {
-                            *incr += 1;
-                        },
-                        false => {
-                            match output.parse::<usize>() {
-                                Ok(length) => {
-                                    match length == 0 {
-                                        true => {
-                                            return Ok(length);
-                                        },
-                                        false => {
-                                            return Err(Error::Incomplete(format!("No more data after length (name: {}) field at position {}.",name,*incr)));
-                                        }
-                                    }
-                                },
-                                Err(_) => {
-                                    return Err(Error::BadStructure(format!("Could not parse the length field. Contains: {}",output)));
-                                }
-                            }
-                        }
-                    }
-    
+                    *incr += 1;
+
                     match output.parse::<usize>() {
                         Ok(length) => {
                             return Ok(length);
                         },
                         Err(_) => {
-                            return Err(Error::BadStructure(format!("Could not parse the length field. Contains: {}",output)));
+                            return Err(Error::BadStructure(format!("Could not parse the length field (name: {}). Contains: {}",name,output)));
                         }
                     }
-    
                 },
                 c => {
                     output.push(c);
                 }
             }
-    
-            match *incr + 1 < bytes.len() {
-                true => {
-                    *incr += 1;
-                },
-                false => {
-                    return Err(Error::NoStructure);
-                }
-            }
-    
+
+            *incr += 1;
+
         }
     }
 
@@ -336,170 +493,668 @@ impl MiniON {
     /// ```
     /// ## Warning!
     /// Should not be called when the object has a length of 0! This will result in errors!
-    pub fn parse_content(bytes: &[u8], incr: &mut usize, name: &str, length: usize) -> Result<String,Error> {
-        let mut output = String::new();
-    
-        let mut pos_count: usize = 0;
-    
-        loop {
-    
-            pos_count += 1;
-    
-            output.push(bytes[*incr] as char);
-    
-            match *incr + 1 < bytes.len() {
-                true => {
-                    match pos_count < length {
-                        true => {
-                            *incr += 1;
-                        },
-                        false => {
-                            *incr += 1;
-    
-                            return Ok(output);
-                        }
-                    }
-                },
-                false => {
-    
-                    match pos_count == length {
-                        true => {
-                            *incr += 1;
-
-                            return Ok(output);
-                        },
-                        false => {
-                            return Err(Error::Incomplete(format!("The object (name: {}) is incomplete. Bytes missing = {} .",name, length - pos_count )));
-                        }
+    pub fn parse_content(bytes: &[u8], incr: &mut usize, _name: &str, length: usize) -> Result<String,Error> {
+        match MiniON::parse_content_bytes(bytes, incr, length) {
+            Ok(content) => {
+                match String::from_utf8(content) {
+                    Ok(content) => {
+                        return Ok(content);
+                    },
+                    Err(_) => {
+                        return Err(Error::BadStructure(format!("The content starting at position {} is not valid UTF-8.",*incr - length)));
                     }
-                    
                 }
-            }
-    
-        }
-
-    }
-    
-}
-
-pub enum Error {
-    Incomplete(String),
-    NoStructure,
-    BadStructure(String),
-    NoContent,
-}
-
-impl Error {
-    /// Will `println!` the error with an explanation for you. 
-    pub fn print(&self) {
-        match self {
-            Error::Incomplete(info) => {
-                println!("Error: Incomplete data: {}",info);
-            },
-            Error::NoStructure => {
-                println!("Error: No structure: The data does not follow the mON structure.")
-            },
-            Error::BadStructure(info) => {
-                println!("Error: Bad data: {}",info);
             },
-            Error::NoContent => {
-                println!("Error: Content of length 0 cannot be parsed.")
+            Err(e) => {
+                return Err(e);
             }
         }
     }
 
-    /// Will give you a `String` with the relevant info.
-    pub fn to_string(&self) -> String {
-        match self {
-            Error::Incomplete(info) => {
-                return format!("Error: Incomplete data: {}",info);
-            },
-            Error::NoStructure => {
-                return format!("Error: No structure: The data does not follow the mON structure.")
-            },
-            Error::BadStructure(info) => {
-                return format!("Error: Bad data: {}",info);
-            },
-            Error::NoContent => {
-                return format!("Error: Content of length 0 cannot be parsed.")
-            }
+    /// Parse the raw, binary-safe contents of a miniON object (after having parsed the name and length tags).
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"image|4~\xFF\x00|~";
+    ///
+    ///     let mut incr: usize = 8;
+    ///
+    ///     match MiniON::parse_content_bytes(data, &mut incr, 4) {
+    ///         Ok(content) => {
+    ///             assert_eq!(vec![0xFF, 0x00, b'|', b'~'],content);
+    ///             assert_eq!(incr,data.len());
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    /// ## Note
+    /// A `length` of 0 returns an empty `Vec` and leaves `incr` where it was.
+    pub fn parse_content_bytes(bytes: &[u8], incr: &mut usize, length: usize) -> Result<Vec<u8>,Error> {
+        if *incr + length > bytes.len() {
+            return Err(Error::Needed((*incr + length) - bytes.len()));
         }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_create_minion() {
-        let mut minion = MiniON::new("greeting".to_string());
-
-        minion.set_content("Hello, world!".to_string());
-
-        assert_eq!("greeting|13~Hello, world!",minion.as_string());
-
-    }
-
-    #[test]
-    fn test_multi_minion() {
-        
-        let mut minion_container = MiniON::new("container".to_string());
-
-        let mut days_of_the_week = MiniON::new("object".to_string());
-
-        days_of_the_week.set_content("____________________".to_string());
-
-        let mut pairs_of_socks = MiniON::new("object".to_string());
-
-        pairs_of_socks.set_content("____________________".to_string());
 
-        let mut content = days_of_the_week.as_string();
-        content.push_str(&pairs_of_socks.as_string());
+        let content = bytes[*incr..*incr + length].to_vec();
 
-        minion_container.set_content(content);
+        *incr += length;
 
-        assert_eq!("container|60~object|20~____________________object|20~____________________",minion_container.as_string());
+        return Ok(content);
     }
 
-    #[test]
-    fn test_parse_manually() {
-        let data = b"greeting|13~Hello, world!name|6~miniON";
-
-        let mut incr: usize = 0;
+    /// Parse data into a `MiniON` object the binary-safe way, the way [`MiniON::parse_one`] does for UTF-8 content.
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"image|4~\xFF\x00|~";
+    ///
+    ///     let mut incr: usize = 0;
+    ///
+    ///     match MiniON::parse_one_binary(data, &mut incr) {
+    ///         Ok(minion) => {
+    ///             assert_eq!("image",minion.name);
+    ///             assert_eq!(Some(vec![0xFF, 0x00, b'|', b'~']),minion.content_bytes());
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn parse_one_binary(bytes: &[u8], incr: &mut usize) -> Result<MiniON,Error> {
+        let name: String;
+        let length: usize;
 
-        match MiniON::parse_name(data, &mut incr) {
-            Ok(name) => {
-                assert_eq!("greeting",name);
+        match MiniON::parse_name(bytes, incr) {
+            Ok(n) => {
+                name = n;
             },
             Err(e) => {
-                panic!("{}",e.to_string());
+                return Err(e);
             }
         }
 
-        match MiniON::parse_length(data, &mut incr, "greeting") {
-            Ok(length) => {
-                assert_eq!(13,length);
+        match MiniON::parse_length(bytes, incr, &name) {
+            Ok(n) => {
+                length = n;
             },
             Err(e) => {
-                panic!("{}",e.to_string());
+                return Err(e);
             }
         }
 
-        match MiniON::parse_content(data, &mut incr, "greeting", 13) {
+        if length == 0 {
+            return Ok(MiniON::new(name));
+        }
+
+        match MiniON::parse_content_bytes(bytes, incr, length) {
             Ok(content) => {
-                assert_eq!("Hello, world!",content);
+                return Ok(MiniON::new_binary(name, content));
             },
             Err(e) => {
-                panic!("{}",e.to_string());
+                return Err(e);
             }
         }
+    }
 
-        match MiniON::parse_name(data, &mut incr) {
-            Ok(name) => {
-                assert_eq!("name",name);
-            },
-            Err(e) => {
+    /// Parse the name of a miniON object as a borrowed slice of `bytes`. (Start at the correct position.)
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"greeting|13~Hello, world!";
+    ///
+    ///     let mut incr: usize = 0;
+    ///
+    ///     match MiniON::parse_name_ref(data,&mut incr) {
+    ///         Ok(name) => {
+    ///             assert_eq!("greeting",name);
+    ///             assert_eq!(9,incr);
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn parse_name_ref<'a>(bytes: &'a [u8], incr: &mut usize) -> Result<&'a str,Error> {
+        let start = *incr;
+
+        loop {
+
+            if *incr >= bytes.len() {
+                return Err(Error::Needed(1));
+            }
+
+            match bytes[*incr] {
+                b'|' => {
+                    let name = match std::str::from_utf8(&bytes[start..*incr]) {
+                        Ok(name) => name,
+                        Err(_) => {
+                            return Err(Error::BadStructure(format!("The name field starting at position {} is not valid UTF-8.",start)));
+                        }
+                    };
+
+                    *incr += 1;
+
+                    return Ok(name);
+                },
+                _ => {
+                    *incr += 1;
+                }
+            }
+
+        }
+    }
+
+    /// Parse the length of a miniON object as a borrowed slice of `bytes` (after having parsed the name tag).
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"greeting|13~Hello, world!";
+    ///
+    ///     let mut incr: usize = 9;
+    ///
+    ///     match MiniON::parse_length_ref(data,&mut incr,"greeting") {
+    ///         Ok(length) => {
+    ///             assert_eq!(13,length);
+    ///             assert_eq!(12,incr);
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn parse_length_ref(bytes: &[u8], incr: &mut usize, name: &str) -> Result<usize,Error> {
+        let start = *incr;
+
+        loop {
+
+            if *incr >= bytes.len() {
+                return Err(Error::Needed(1));
+            }
+
+            match bytes[*incr] {
+                b'~' => {
+                    let digits = match std::str::from_utf8(&bytes[start..*incr]) {
+                        Ok(digits) => digits,
+                        Err(_) => {
+                            return Err(Error::BadStructure(format!("The length field (name: {}) is not valid UTF-8.",name)));
+                        }
+                    };
+
+                    *incr += 1;
+
+                    match digits.parse::<usize>() {
+                        Ok(length) => {
+                            return Ok(length);
+                        },
+                        Err(_) => {
+                            return Err(Error::BadStructure(format!("Could not parse the length field (name: {}). Contains: {}",name,digits)));
+                        }
+                    }
+                },
+                _ => {
+                    *incr += 1;
+                }
+            }
+
+        }
+    }
+
+    /// Parse the contents of a miniON object as a borrowed slice of `bytes` (after having parsed the name and length tags).
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"greeting|13~Hello, world!";
+    ///
+    ///     let mut incr: usize = 12;
+    ///
+    ///     match MiniON::parse_content_ref(data, &mut incr, 13) {
+    ///         Ok(content) => {
+    ///             assert_eq!("Hello, world!",content);
+    ///             assert_eq!(incr,data.len());
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    /// ## Note
+    /// A `length` of 0 returns an empty `&str` and leaves `incr` where it was.
+    pub fn parse_content_ref<'a>(bytes: &'a [u8], incr: &mut usize, length: usize) -> Result<&'a str,Error> {
+        if *incr + length > bytes.len() {
+            return Err(Error::Needed((*incr + length) - bytes.len()));
+        }
+
+        let content = match std::str::from_utf8(&bytes[*incr..*incr + length]) {
+            Ok(content) => content,
+            Err(_) => {
+                return Err(Error::BadStructure(format!("The content starting at position {} is not valid UTF-8.",*incr)));
+            }
+        };
+
+        *incr += length;
+
+        return Ok(content);
+    }
+
+    /// Parse data into a borrowed [`MiniONRef`], slicing `bytes` instead of allocating.
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"greeting|13~Hello, world!";
+    ///
+    ///     let mut incr: usize = 0;
+    ///
+    ///     match MiniON::parse_one_ref(data, &mut incr) {
+    ///         Ok(minion) => {
+    ///             assert_eq!("greeting",minion.name);
+    ///             assert_eq!(Some("Hello, world!"),minion.content);
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn parse_one_ref<'a>(bytes: &'a [u8], incr: &mut usize) -> Result<MiniONRef<'a>,Error> {
+        let name: &'a str;
+        let length: usize;
+        let mut content: Option<&'a str> = None;
+
+        match MiniON::parse_name_ref(bytes, incr) {
+            Ok(n) => {
+                name = n;
+            },
+            Err(e) => {
+                return Err(e);
+            }
+        }
+
+        match MiniON::parse_length_ref(bytes, incr, name) {
+            Ok(n) => {
+                length = n;
+            },
+            Err(e) => {
+                return Err(e);
+            }
+        }
+
+        if length != 0 {
+            match MiniON::parse_content_ref(bytes, incr, length) {
+                Ok(n) => {
+                    content = Some(n);
+                },
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        }
+
+        return Ok(MiniONRef { name, length, content });
+    }
+
+    /// Parse data that contains multiple miniON objects ONE AFTER THE OTHER into borrowed
+    /// [`MiniONRef`]s. Will not parse nested miniON objects, same as [`MiniON::parse_all`].
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"first|4~ONE,second|4~TWO,third|5~THREE";
+    ///
+    ///     match MiniON::parse_all_ref(data) {
+    ///         Ok(minions) => {
+    ///             assert_eq!(3,minions.len());
+    ///
+    ///             assert_eq!("first",minions[0].name);
+    ///             assert_eq!(Some("ONE,"),minions[0].content);
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn parse_all_ref<'a>(bytes: &'a [u8]) -> Result<Vec<MiniONRef<'a>>,Error> {
+        let mut minions: Vec<MiniONRef<'a>> = Vec::new();
+
+        let mut incr: usize = 0;
+
+        loop {
+            match MiniON::parse_one_ref(bytes, &mut incr) {
+                Ok(minion) => {
+                    minions.push(minion);
+
+                    if incr == bytes.len() {
+                        return Ok(minions);
+                    }
+                },
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Parse data that contains multiple miniON objects ONE AFTER THE OTHER into a tree, recursively re-parsing each object's content as further miniON objects.
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"title|12~grocery listgrocery list|21~1.|6~cheese2.|5~bread";
+    ///
+    ///     match MiniON::parse_tree(data) {
+    ///         Ok(tree) => {
+    ///             assert_eq!(2,tree.len());
+    ///
+    ///             assert_eq!("title",tree[0].name);
+    ///             assert_eq!(0,tree[0].children.len());
+    ///
+    ///             assert_eq!("grocery list",tree[1].name);
+    ///             assert_eq!(2,tree[1].children.len());
+    ///             assert_eq!("1.",tree[1].children[0].name);
+    ///             assert_eq!(Some("cheese"),tree[1].children[0].content.as_deref());
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn parse_tree(bytes: &[u8]) -> Result<Vec<MiniONNode>,Error> {
+        return MiniON::parse_tree_with_depth(bytes, DEFAULT_MAX_TREE_DEPTH);
+    }
+
+    /// Same as [`MiniON::parse_tree`], but with an explicit recursion limit instead of
+    /// [`DEFAULT_MAX_TREE_DEPTH`], to guard against pathologically deep nesting.
+    pub fn parse_tree_with_depth(bytes: &[u8], max_depth: usize) -> Result<Vec<MiniONNode>,Error> {
+        match MiniON::parse_all(bytes) {
+            Ok(minions) => {
+                let mut nodes: Vec<MiniONNode> = Vec::new();
+
+                for minion in minions {
+                    nodes.push(MiniONNode::from_minion(minion, max_depth));
+                }
+
+                return Ok(nodes);
+            },
+            Err(e) => {
+                return Err(e);
+            }
+        }
+    }
+
+    /// Walk the whole buffer confirming every declared `length` field exactly matches the bytes available, with no trailing garbage left over.
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"greeting|13~Hello, world!";
+    ///
+    ///     match MiniON::validate(data) {
+    ///         Ok(()) => {},
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    ///
+    ///     let truncated = b"greeting|13~Hello";
+    ///
+    ///     match MiniON::validate(truncated) {
+    ///         Ok(()) => {
+    ///             panic!("Expected an error!");
+    ///         },
+    ///         Err(Error::BadStructure(_)) => {},
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn validate(bytes: &[u8]) -> Result<(),Error> {
+        let mut incr: usize = 0;
+
+        loop {
+            let start = incr;
+
+            let name = match MiniON::parse_name_ref(bytes, &mut incr) {
+                Ok(name) => name,
+                Err(Error::Needed(_)) => {
+                    return Err(Error::BadStructure(format!("The name field starting at position {} is incomplete.",start)));
+                },
+                Err(e) => {
+                    return Err(e);
+                }
+            };
+
+            let length_start = incr;
+
+            let length = match MiniON::parse_length_ref(bytes, &mut incr, name) {
+                Ok(length) => length,
+                Err(Error::Needed(_)) => {
+                    return Err(Error::BadStructure(format!("The length field starting at position {} is incomplete.",length_start)));
+                },
+                Err(e) => {
+                    return Err(e);
+                }
+            };
+
+            if incr + length > bytes.len() {
+                return Err(Error::BadStructure(format!("The content starting at position {} declares a length of {} byte(s), but only {} byte(s) remain.",incr,length,bytes.len() - incr)));
+            }
+
+            incr += length;
+
+            if incr == bytes.len() {
+                return Ok(());
+            }
+        }
+    }
+
+}
+
+/// A borrowed, zero-copy view over a single miniON object. Convert to an owned [`MiniON`] with `.into()`.
+#[derive(Clone, Copy)]
+pub struct MiniONRef<'a> {
+    pub name: &'a str,
+    pub length: usize,
+    pub content: Option<&'a str>,
+}
+
+impl<'a> From<MiniONRef<'a>> for MiniON {
+    fn from(minion_ref: MiniONRef<'a>) -> MiniON {
+        MiniON {
+            name: minion_ref.name.to_string(),
+            length: minion_ref.length,
+            content: minion_ref.content.map(|content| content.to_string()),
+            content_bytes: None,
+        }
+    }
+}
+
+/// Default recursion limit for [`MiniON::parse_tree`].
+pub const DEFAULT_MAX_TREE_DEPTH: usize = 32;
+
+/// A node in a miniON tree produced by [`MiniON::parse_tree`]: like `MiniON`, but with `content` recursively re-parsed into `children`.
+pub struct MiniONNode {
+    pub name: String,
+    pub length: usize,
+    pub content: Option<String>,
+    pub children: Vec<MiniONNode>,
+}
+
+impl MiniONNode {
+    fn from_minion(minion: MiniON, max_depth: usize) -> MiniONNode {
+        let children = match (&minion.content, max_depth) {
+            (Some(content), depth) if depth > 0 => {
+                match MiniON::parse_all(content.as_bytes()) {
+                    Ok(child_minions) => {
+                        child_minions.into_iter().map(|child| MiniONNode::from_minion(child, depth - 1)).collect()
+                    },
+                    Err(_) => {
+                        Vec::new()
+                    }
+                }
+            },
+            _ => {
+                Vec::new()
+            }
+        };
+
+        MiniONNode {
+            name: minion.name,
+            length: minion.length,
+            content: minion.content,
+            children,
+        }
+    }
+
+    /// Return the node (and, recursively, its children) as a `String`, the tree counterpart to [`MiniON::as_string`].
+    /// ## Example
+    /// ```rust
+    ///     use minimal_object_notation::*;
+    ///
+    ///     let data = b"title|12~grocery listgrocery list|21~1.|6~cheese2.|5~bread";
+    ///
+    ///     match MiniON::parse_tree(data) {
+    ///         Ok(tree) => {
+    ///             let rendered: String = tree.iter().map(|node| node.as_string()).collect();
+    ///
+    ///             assert_eq!(String::from_utf8(data.to_vec()).unwrap(),rendered);
+    ///         },
+    ///         Err(e) => {
+    ///             panic!("{}",e.to_string());
+    ///         }
+    ///     }
+    /// ```
+    pub fn as_string(&self) -> String {
+        let content = if self.children.is_empty() {
+            self.content.clone().unwrap_or_default()
+        } else {
+            self.children.iter().map(|child| child.as_string()).collect()
+        };
+
+        let mut output = String::from(&self.name);
+        output.push('|');
+        output.push_str(&content.len().to_string());
+        output.push('~');
+        output.push_str(&content);
+
+        return output;
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Incomplete(String),
+    BadStructure(String),
+    NoContent,
+    /// Not enough bytes were available to finish parsing. The `usize` is how many more bytes are needed.
+    Needed(usize),
+}
+
+impl Error {
+    /// Will `println!` the error with an explanation for you.
+    pub fn print(&self) {
+        println!("{}",self);
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Incomplete(info) => {
+                write!(f,"Error: Incomplete data: {}",info)
+            },
+            Error::BadStructure(info) => {
+                write!(f,"Error: Bad data: {}",info)
+            },
+            Error::NoContent => {
+                write!(f,"Error: Content of length 0 cannot be parsed.")
+            },
+            Error::Needed(n) => {
+                write!(f,"Error: Needed: {} more byte(s) are needed to continue parsing.",n)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_minion() {
+        let mut minion = MiniON::new("greeting".to_string());
+
+        minion.set_content("Hello, world!".to_string());
+
+        assert_eq!("greeting|13~Hello, world!",minion.as_string().unwrap());
+
+    }
+
+    #[test]
+    fn test_multi_minion() {
+        
+        let mut minion_container = MiniON::new("container".to_string());
+
+        let mut days_of_the_week = MiniON::new("object".to_string());
+
+        days_of_the_week.set_content("____________________".to_string());
+
+        let mut pairs_of_socks = MiniON::new("object".to_string());
+
+        pairs_of_socks.set_content("____________________".to_string());
+
+        let mut content = days_of_the_week.as_string().unwrap();
+        content.push_str(&pairs_of_socks.as_string().unwrap());
+
+        minion_container.set_content(content);
+
+        assert_eq!("container|60~object|20~____________________object|20~____________________",minion_container.as_string().unwrap());
+    }
+
+    #[test]
+    fn test_parse_manually() {
+        let data = b"greeting|13~Hello, world!name|6~miniON";
+
+        let mut incr: usize = 0;
+
+        match MiniON::parse_name(data, &mut incr) {
+            Ok(name) => {
+                assert_eq!("greeting",name);
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+
+        match MiniON::parse_length(data, &mut incr, "greeting") {
+            Ok(length) => {
+                assert_eq!(13,length);
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+
+        match MiniON::parse_content(data, &mut incr, "greeting", 13) {
+            Ok(content) => {
+                assert_eq!("Hello, world!",content);
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+
+        match MiniON::parse_name(data, &mut incr) {
+            Ok(name) => {
+                assert_eq!("name",name);
+            },
+            Err(e) => {
                 panic!("{}",e.to_string());
             }
         }
@@ -628,4 +1283,347 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_parse_from_reader() {
+        use std::io::BufReader;
+
+        let data = b"greeting|13~Hello, world!";
+
+        let mut reader = BufReader::new(&data[..]);
+
+        match MiniON::parse_from_reader(&mut reader) {
+            Ok(minion) => {
+                assert_eq!("greeting",minion.name);
+
+                match minion.content {
+                    Some(content) => {
+                        assert_eq!("Hello, world!",content);
+                    },
+                    None => {
+                        panic!("Expected content!");
+                    }
+                }
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    /// A `Read` that only ever hands out one byte at a time, to force `parse_from_reader`
+    /// to resume across several `Error::Needed` rounds instead of getting the whole
+    /// object in a single `fill_buf` call.
+    struct OneByteAtATime<'a> {
+        remaining: &'a [u8],
+    }
+
+    impl<'a> std::io::Read for OneByteAtATime<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.remaining.split_first() {
+                Some((first, rest)) => {
+                    buf[0] = *first;
+                    self.remaining = rest;
+
+                    Ok(1)
+                },
+                None => {
+                    Ok(0)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_from_reader_trickling_bytes() {
+        use std::io::BufReader;
+
+        let data = b"greeting|13~Hello, world!";
+
+        let mut reader = BufReader::new(OneByteAtATime { remaining: data });
+
+        match MiniON::parse_from_reader(&mut reader) {
+            Ok(minion) => {
+                assert_eq!("greeting",minion.name);
+
+                match minion.content {
+                    Some(content) => {
+                        assert_eq!("Hello, world!",content);
+                    },
+                    None => {
+                        panic!("Expected content!");
+                    }
+                }
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_name_needs_more_data() {
+        let data = b"greet";
+
+        let mut incr: usize = 0;
+
+        match MiniON::parse_name(data, &mut incr) {
+            Ok(_) => {
+                panic!("Expected an error!");
+            },
+            Err(Error::Needed(n)) => {
+                assert_eq!(1,n);
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_all_ref() {
+        let data = b"first|4~ONE,second|4~TWO,third|6~THREE,container|29~name|5~NAME,content|7~CONTENT";
+
+        match MiniON::parse_all_ref(data) {
+            Ok(minions) => {
+                assert_eq!(4,minions.len());
+
+                assert_eq!("first",minions[0].name);
+                assert_eq!(Some("ONE,"),minions[0].content);
+
+                assert_eq!("container",minions[3].name);
+                assert_eq!(Some("name|5~NAME,content|7~CONTENT"),minions[3].content);
+
+                let owned: MiniON = minions[3].into();
+
+                assert_eq!("container",owned.name);
+                assert_eq!(Some("name|5~NAME,content|7~CONTENT".to_string()),owned.content);
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_one_ref_rejects_invalid_utf8() {
+        let data = b"greeting|3~\xFF\xFF\xFF";
+
+        let mut incr: usize = 0;
+
+        match MiniON::parse_one_ref(data, &mut incr) {
+            Ok(_) => {
+                panic!("Expected an error!");
+            },
+            Err(Error::BadStructure(_)) => {},
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_content_handles_multi_byte_utf8() {
+        let data = "emoji|4~\u{1F600}".as_bytes();
+
+        let mut incr: usize = 0;
+
+        match MiniON::parse_one(data, &mut incr) {
+            Ok(minion) => {
+                assert_eq!("emoji",minion.name);
+
+                match minion.content {
+                    Some(content) => {
+                        assert_eq!("\u{1F600}",content);
+                    },
+                    None => {
+                        panic!("Expected content!");
+                    }
+                }
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_name_handles_multi_byte_utf8() {
+        let data = "café|5~hello".as_bytes();
+
+        let mut incr: usize = 0;
+
+        match MiniON::parse_one(data, &mut incr) {
+            Ok(minion) => {
+                assert_eq!("café",minion.name);
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_binary_round_trip() {
+        let minion = MiniON::new_binary("image".to_string(), vec![0xFF, 0x00, b'|', b'~']);
+
+        assert_eq!(4,minion.length);
+        assert_eq!(None,minion.content);
+        assert_eq!(Some(vec![0xFF, 0x00, b'|', b'~']),minion.content_bytes());
+
+        let rendered = minion.as_bytes();
+
+        assert_eq!(b"image|4~\xFF\x00|~".to_vec(),rendered);
+
+        let mut incr: usize = 0;
+
+        match MiniON::parse_one_binary(&rendered, &mut incr) {
+            Ok(roundtripped) => {
+                assert_eq!("image",roundtripped.name);
+                assert_eq!(Some(vec![0xFF, 0x00, b'|', b'~']),roundtripped.content_bytes());
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_as_string_rejects_non_utf8_binary_content() {
+        let minion = MiniON::new_binary("image".to_string(), vec![0xFF, 0x00, 0x01, 0x02]);
+
+        match minion.as_string() {
+            Ok(s) => {
+                panic!("Expected an error, got {}",s);
+            },
+            Err(Error::BadStructure(_)) => {},
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_content_bytes_accessor_falls_back_to_text_content() {
+        let mut minion = MiniON::new("greeting".to_string());
+
+        minion.set_content("Hello, world!".to_string());
+
+        assert_eq!(Some("Hello, world!".as_bytes().to_vec()),minion.content_bytes());
+    }
+
+    #[test]
+    fn test_parse_tree() {
+        let data = b"title|12~grocery listdate|10~04/08/2020grocery list|21~1.|6~cheese2.|5~bread";
+
+        match MiniON::parse_tree(data) {
+            Ok(tree) => {
+                assert_eq!(3,tree.len());
+
+                assert_eq!("title",tree[0].name);
+                assert_eq!(0,tree[0].children.len());
+                assert_eq!(Some("grocery list"),tree[0].content.as_deref());
+
+                assert_eq!("grocery list",tree[2].name);
+                assert_eq!(2,tree[2].children.len());
+
+                assert_eq!("1.",tree[2].children[0].name);
+                assert_eq!(Some("cheese"),tree[2].children[0].content.as_deref());
+                assert_eq!(0,tree[2].children[0].children.len());
+
+                assert_eq!("2.",tree[2].children[1].name);
+                assert_eq!(Some("bread"),tree[2].children[1].content.as_deref());
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_tree_as_string_round_trip() {
+        let data = b"title|12~grocery listgrocery list|21~1.|6~cheese2.|5~bread";
+
+        match MiniON::parse_tree(data) {
+            Ok(tree) => {
+                let rendered: String = tree.iter().map(|node| node.as_string()).collect();
+
+                assert_eq!(String::from_utf8(data.to_vec()).expect("data is valid UTF-8"),rendered);
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_tree_respects_depth_limit() {
+        let data = b"outer|21~inner|12~leaf|5~HELLO";
+
+        match MiniON::parse_tree_with_depth(data, 0) {
+            Ok(tree) => {
+                assert_eq!(1,tree.len());
+                assert_eq!("outer",tree[0].name);
+                assert_eq!(0,tree[0].children.len());
+                assert_eq!(Some("inner|12~leaf|5~HELLO"),tree[0].content.as_deref());
+            },
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_data() {
+        let data = b"title|12~grocery listdate|10~04/08/2020grocery list|21~1.|6~cheese2.|5~bread";
+
+        match MiniON::validate(data) {
+            Ok(()) => {},
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_truncated_content() {
+        let data = b"greeting|13~Hello";
+
+        match MiniON::validate(data) {
+            Ok(()) => {
+                panic!("Expected an error!");
+            },
+            Err(Error::BadStructure(_)) => {},
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_trailing_garbage() {
+        let data = b"greeting|13~Hello, world!garbage";
+
+        match MiniON::validate(data) {
+            Ok(()) => {
+                panic!("Expected an error!");
+            },
+            Err(Error::BadStructure(_)) => {},
+            Err(e) => {
+                panic!("{}",e.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_display_and_std_error() {
+        let error = Error::BadStructure("example".to_string());
+
+        assert_eq!("Error: Bad data: example",error.to_string());
+        assert_eq!("Error: Bad data: example",format!("{}",error));
+
+        let as_std_error: &dyn std::error::Error = &error;
+
+        assert_eq!("Error: Bad data: example",as_std_error.to_string());
+    }
 }
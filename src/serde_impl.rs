@@ -0,0 +1,694 @@
+//! serde `Serialize` / `Deserialize` support for the mON format, gated behind the `serde`
+//! cargo feature so the core crate stays dependency-free without it.
+
+use crate::{Error, MiniON, MiniONRef};
+use serde::de::IntoDeserializer;
+use serde::Deserializer as SerdeDeserializer;
+use serde::Serializer as SerdeSerializer;
+use std::fmt;
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::BadStructure(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::BadStructure(msg.to_string())
+    }
+}
+
+/// Serialize `value` to an mON `String`, the way `serde_json::to_string` works for JSON.
+/// ## Example
+/// ```rust
+///     use minimal_object_notation::serde_impl::to_string;
+///     use serde::Serialize;
+///
+///     #[derive(Serialize)]
+///     struct Greeting {
+///         name: String,
+///     }
+///
+///     match to_string(&Greeting { name: "world".to_string() }) {
+///         Ok(output) => {
+///             assert_eq!("name|5~world",output);
+///         },
+///         Err(e) => {
+///             panic!("{}",e.to_string());
+///         }
+///     }
+/// ```
+pub fn to_string<T: serde::Serialize>(value: &T) -> Result<String, Error> {
+    let mut serializer = Serializer::new();
+
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.output)
+}
+
+/// Deserialize a `T` out of mON-encoded `input`, the way `serde_json::from_str` works for JSON.
+/// ## Example
+/// ```rust
+///     use minimal_object_notation::serde_impl::from_str;
+///     use serde::Deserialize;
+///
+///     #[derive(Deserialize)]
+///     struct Greeting {
+///         name: String,
+///     }
+///
+///     match from_str::<Greeting>("name|5~world") {
+///         Ok(greeting) => {
+///             assert_eq!("world",greeting.name);
+///         },
+///         Err(e) => {
+///             panic!("{}",e.to_string());
+///         }
+///     }
+/// ```
+pub fn from_str<'de, T: serde::Deserialize<'de>>(input: &'de str) -> Result<T, Error> {
+    let mut deserializer = Deserializer::from_str(input);
+
+    T::deserialize(&mut deserializer)
+}
+
+/// A `serde::Serializer` that renders struct fields, map entries and sequence elements as mON objects.
+pub struct Serializer {
+    output: String,
+    index: usize,
+    pending_key: Option<String>,
+}
+
+impl Serializer {
+    fn new() -> Serializer {
+        Serializer {
+            output: String::new(),
+            index: 0,
+            pending_key: None,
+        }
+    }
+}
+
+macro_rules! serialize_display {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, v: $ty) -> Result<(), Error> {
+            self.output.push_str(&v.to_string());
+
+            Ok(())
+        }
+    };
+}
+
+impl SerdeSerializer for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    serialize_display!(serialize_bool, bool);
+    serialize_display!(serialize_i8, i8);
+    serialize_display!(serialize_i16, i16);
+    serialize_display!(serialize_i32, i32);
+    serialize_display!(serialize_i64, i64);
+    serialize_display!(serialize_u8, u8);
+    serialize_display!(serialize_u16, u16);
+    serialize_display!(serialize_u32, u32);
+    serialize_display!(serialize_u64, u64);
+    serialize_display!(serialize_f32, f32);
+    serialize_display!(serialize_f64, f64);
+    serialize_display!(serialize_char, char);
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.output.push_str(v);
+
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        match std::str::from_utf8(v) {
+            Ok(s) => {
+                self.output.push_str(s);
+
+                Ok(())
+            },
+            Err(_) => {
+                Err(Error::BadStructure("Byte content is not valid UTF-8.".to_string()))
+            }
+        }
+    }
+
+    /// `N`/`S` presence markers keep `None` and `Some("")` distinguishable.
+    fn serialize_none(self) -> Result<(), Error> {
+        self.output.push('N');
+
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, value: &T) -> Result<(), Error> {
+        self.output.push('S');
+
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<(), Error> {
+        self.output.push_str(variant);
+
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _name: &'static str, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> Result<(), Error> {
+        Err(Error::BadStructure("mON does not support enum newtype variants.".to_string()))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        self.index = 0;
+
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.index = 0;
+
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Error> {
+        self.index = 0;
+
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(Error::BadStructure("mON does not support enum tuple variants.".to_string()))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        self.pending_key = None;
+
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, Error> {
+        Err(Error::BadStructure("mON does not support enum struct variants.".to_string()))
+    }
+}
+
+fn render_field(name: &str, value_output: String) -> String {
+    format!("{}|{}~{}", name, value_output.len(), value_output)
+}
+
+fn serialize_to_content<T: ?Sized + serde::Serialize>(value: &T) -> Result<String, Error> {
+    let mut serializer = Serializer::new();
+
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.output)
+}
+
+impl serde::ser::SerializeSeq for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let content = serialize_to_content(value)?;
+
+        self.output.push_str(&render_field(&self.index.to_string(), content));
+
+        self.index += 1;
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeTuple for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl serde::ser::SerializeTupleStruct for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl serde::ser::SerializeTupleVariant for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, _value: &T) -> Result<(), Error> {
+        Err(Error::BadStructure("mON does not support enum tuple variants.".to_string()))
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Err(Error::BadStructure("mON does not support enum tuple variants.".to_string()))
+    }
+}
+
+impl serde::ser::SerializeStructVariant for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, _key: &'static str, _value: &T) -> Result<(), Error> {
+        Err(Error::BadStructure("mON does not support enum struct variants.".to_string()))
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Err(Error::BadStructure("mON does not support enum struct variants.".to_string()))
+    }
+}
+
+impl serde::ser::SerializeMap for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + serde::Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        self.pending_key = Some(serialize_to_content(key)?);
+
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = match self.pending_key.take() {
+            Some(key) => key,
+            None => {
+                return Err(Error::BadStructure("serialize_value was called before serialize_key.".to_string()));
+            }
+        };
+
+        let content = serialize_to_content(value)?;
+
+        self.output.push_str(&render_field(&key, content));
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeStruct for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        let content = serialize_to_content(value)?;
+
+        self.output.push_str(&render_field(key, content));
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// A `serde::Deserializer` built on [`MiniON::parse_all_ref`], recursively re-parsing `content` for nested structs, maps, sequences and tuples.
+pub struct Deserializer<'de> {
+    content: &'de str,
+}
+
+impl<'de> Deserializer<'de> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'de str) -> Deserializer<'de> {
+        Deserializer { content: input }
+    }
+
+    fn objects(&self) -> Result<Vec<MiniONRef<'de>>, Error> {
+        if self.content.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        MiniON::parse_all_ref(self.content.as_bytes())
+    }
+}
+
+struct ObjectMapAccess<'de> {
+    objects: Vec<MiniONRef<'de>>,
+    index: usize,
+}
+
+impl<'de> serde::de::MapAccess<'de> for ObjectMapAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: serde::de::DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        match self.objects.get(self.index) {
+            Some(object) => {
+                let mut key_de = Deserializer { content: object.name };
+
+                seed.deserialize(&mut key_de).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: serde::de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let object = self.objects[self.index];
+
+        self.index += 1;
+
+        let mut value_de = Deserializer { content: object.content.unwrap_or("") };
+
+        seed.deserialize(&mut value_de)
+    }
+}
+
+struct ObjectSeqAccess<'de> {
+    objects: Vec<MiniONRef<'de>>,
+    index: usize,
+}
+
+impl<'de> serde::de::SeqAccess<'de> for ObjectSeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: serde::de::DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+        match self.objects.get(self.index) {
+            Some(object) => {
+                self.index += 1;
+
+                let mut value_de = Deserializer { content: object.content.unwrap_or("") };
+
+                seed.deserialize(&mut value_de).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.objects.len() - self.index)
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            match self.content.parse::<$ty>() {
+                Ok(v) => visitor.$visit(v),
+                Err(_) => Err(Error::BadStructure(format!("Could not parse \"{}\" as {}.",self.content,stringify!($ty)))),
+            }
+        }
+    };
+}
+
+impl<'de> SerdeDeserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bool<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.content {
+            "true" => visitor.visit_bool(true),
+            "false" => visitor.visit_bool(false),
+            other => Err(Error::BadStructure(format!("Could not parse \"{}\" as a bool.",other))),
+        }
+    }
+
+    deserialize_parsed!(deserialize_i8, visit_i8, i8);
+    deserialize_parsed!(deserialize_i16, visit_i16, i16);
+    deserialize_parsed!(deserialize_i32, visit_i32, i32);
+    deserialize_parsed!(deserialize_i64, visit_i64, i64);
+    deserialize_parsed!(deserialize_u8, visit_u8, u8);
+    deserialize_parsed!(deserialize_u16, visit_u16, u16);
+    deserialize_parsed!(deserialize_u32, visit_u32, u32);
+    deserialize_parsed!(deserialize_u64, visit_u64, u64);
+    deserialize_parsed!(deserialize_f32, visit_f32, f32);
+    deserialize_parsed!(deserialize_f64, visit_f64, f64);
+    deserialize_parsed!(deserialize_char, visit_char, char);
+
+    fn deserialize_str<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_borrowed_str(self.content)
+    }
+
+    fn deserialize_string<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_borrowed_bytes(self.content.as_bytes())
+    }
+
+    fn deserialize_byte_buf<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let mut chars = self.content.chars();
+
+        match chars.next() {
+            Some('N') => visitor.visit_none(),
+            Some('S') => {
+                let mut inner = Deserializer { content: chars.as_str() };
+
+                visitor.visit_some(&mut inner)
+            },
+            _ => {
+                Err(Error::BadStructure(format!("Expected an Option presence marker (\"S\" or \"N\"), found \"{}\".",self.content)))
+            }
+        }
+    }
+
+    fn deserialize_unit<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: serde::de::Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: serde::de::Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let objects = self.objects()?;
+
+        visitor.visit_seq(ObjectSeqAccess { objects, index: 0 })
+    }
+
+    fn deserialize_tuple<V: serde::de::Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: serde::de::Visitor<'de>>(self, _name: &'static str, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let objects = self.objects()?;
+
+        visitor.visit_map(ObjectMapAccess { objects, index: 0 })
+    }
+
+    fn deserialize_struct<V: serde::de::Visitor<'de>>(self, _name: &'static str, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V: serde::de::Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.content.into_deserializer())
+    }
+
+    fn deserialize_identifier<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    fn ok<T>(result: Result<T, Error>) -> T {
+        match result {
+            Ok(value) => value,
+            Err(e) => panic!("{}",e.to_string()),
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Greeting {
+        name: String,
+        age: u32,
+    }
+
+    #[test]
+    fn test_round_trip_struct() {
+        let greeting = Greeting { name: "world".to_string(), age: 7 };
+
+        let encoded = ok(to_string(&greeting));
+
+        assert_eq!("name|5~worldage|1~7",encoded);
+
+        let decoded: Greeting = ok(from_str(&encoded));
+
+        assert_eq!(greeting,decoded);
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Container {
+        title: String,
+        inner: Greeting,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_round_trip_nested_struct_and_seq() {
+        let container = Container {
+            title: "grocery list".to_string(),
+            inner: Greeting { name: "socks".to_string(), age: 2 },
+            tags: vec!["a".to_string(), "bb".to_string(), "ccc".to_string()],
+        };
+
+        let encoded = ok(to_string(&container));
+
+        let decoded: Container = ok(from_str(&encoded));
+
+        assert_eq!(container,decoded);
+    }
+
+    #[test]
+    fn test_round_trip_empty_seq() {
+        let container = Container {
+            title: "grocery list".to_string(),
+            inner: Greeting { name: "socks".to_string(), age: 2 },
+            tags: vec![],
+        };
+
+        let encoded = ok(to_string(&container));
+
+        let decoded: Container = ok(from_str(&encoded));
+
+        assert_eq!(container,decoded);
+    }
+
+    #[test]
+    fn test_round_trip_empty_map() {
+        let map: std::collections::BTreeMap<String,i32> = std::collections::BTreeMap::new();
+
+        let encoded = ok(to_string(&map));
+
+        let decoded: std::collections::BTreeMap<String,i32> = ok(from_str(&encoded));
+
+        assert_eq!(map,decoded);
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct WithOption {
+        maybe: Option<String>,
+        always: i32,
+    }
+
+    #[test]
+    fn test_round_trip_option() {
+        let some = WithOption { maybe: Some("x".to_string()), always: -5 };
+
+        let encoded = ok(to_string(&some));
+
+        let decoded: WithOption = ok(from_str(&encoded));
+
+        assert_eq!(some,decoded);
+
+        let none = WithOption { maybe: None, always: 0 };
+
+        let encoded = ok(to_string(&none));
+
+        let decoded: WithOption = ok(from_str(&encoded));
+
+        assert_eq!(none,decoded);
+    }
+
+    #[test]
+    fn test_round_trip_option_distinguishes_empty_some_from_none() {
+        let empty_some = WithOption { maybe: Some("".to_string()), always: 3 };
+
+        let encoded = ok(to_string(&empty_some));
+
+        let decoded: WithOption = ok(from_str(&encoded));
+
+        assert_eq!(empty_some,decoded);
+        assert_ne!(WithOption { maybe: None, always: 3 },decoded);
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Status {
+        Active,
+        Inactive,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct WithEnum {
+        status: Status,
+    }
+
+    #[test]
+    fn test_round_trip_unit_enum() {
+        let with_enum = WithEnum { status: Status::Active };
+
+        let encoded = ok(to_string(&with_enum));
+
+        let decoded: WithEnum = ok(from_str(&encoded));
+
+        assert_eq!(with_enum,decoded);
+    }
+
+    #[test]
+    fn test_round_trip_map() {
+        let mut map: std::collections::BTreeMap<String,i32> = std::collections::BTreeMap::new();
+        map.insert("a".to_string(),1);
+        map.insert("b".to_string(),2);
+
+        let encoded = ok(to_string(&map));
+
+        let decoded: std::collections::BTreeMap<String,i32> = ok(from_str(&encoded));
+
+        assert_eq!(map,decoded);
+    }
+}